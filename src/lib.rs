@@ -8,59 +8,675 @@ fn deg_to_rad(deg: f64) -> f64 {
     deg * PI / 180.0
 }
 
-/// Convert geodetic (lat, lon, h) to ECEF (X, Y, Z)
-/// lat, lon in degrees; h in meters
-pub fn geodetic_to_ecef(lat_deg: f64, lon_deg: f64, h: f64) -> (f64, f64, f64) {
-    let lat = deg_to_rad(lat_deg);
-    let lon = deg_to_rad(lon_deg);
+/// A reference ellipsoid, defined by its semi-major axis `a` (meters) and
+/// first-eccentricity-squared `e2`. All the geodetic/ECEF/ENU conversions are
+/// available as methods here; the free functions of the same names are thin
+/// wrappers around `Ellipsoid::wgs84()`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ellipsoid {
+    pub a: f64,
+    pub e2: f64,
+}
 
-    let sin_lat = lat.sin();
-    let cos_lat = lat.cos();
-    let cos_lon = lon.cos();
-    let sin_lon = lon.sin();
+impl Ellipsoid {
+    /// WGS-84, the default ellipsoid used throughout this crate's free functions.
+    pub const fn wgs84() -> Self {
+        Ellipsoid { a: A, e2: E2 }
+    }
+
+    /// GRS80, as used by NAD83 and most modern national datums.
+    pub fn grs80() -> Self {
+        Ellipsoid::from_a_f(6_378_137.0, 1.0 / 298.257_222_101)
+    }
+
+    /// Build an ellipsoid from its semi-major axis `a` and flattening `f`.
+    pub fn from_a_f(a: f64, f: f64) -> Self {
+        let e2 = f * (2.0 - f);
+        Ellipsoid { a, e2 }
+    }
+
+    /// Build an ellipsoid from its semi-major axis `a` and first-eccentricity-squared `e2`.
+    pub fn from_a_e2(a: f64, e2: f64) -> Self {
+        Ellipsoid { a, e2 }
+    }
+
+    /// Semi-minor axis `b`, derived from `a` and `e2`.
+    pub fn b(&self) -> f64 {
+        self.a * (1.0 - self.e2).sqrt()
+    }
+
+    /// Flattening `f`, derived from `a` and `e2`.
+    pub fn f(&self) -> f64 {
+        1.0 - (1.0 - self.e2).sqrt()
+    }
+
+    /// Convert geodetic (lat, lon, h) to ECEF (X, Y, Z) on this ellipsoid.
+    /// lat, lon in degrees; h in meters
+    pub fn geodetic_to_ecef(&self, lat_deg: f64, lon_deg: f64, h: f64) -> (f64, f64, f64) {
+        let lat = deg_to_rad(lat_deg);
+        let lon = deg_to_rad(lon_deg);
+
+        let sin_lat = lat.sin();
+        let cos_lat = lat.cos();
+        let cos_lon = lon.cos();
+        let sin_lon = lon.sin();
+
+        let n = self.a / (1.0 - self.e2 * sin_lat * sin_lat).sqrt();
+
+        let x = (n + h) * cos_lat * cos_lon;
+        let y = (n + h) * cos_lat * sin_lon;
+        let z = (n * (1.0 - self.e2) + h) * sin_lat;
+
+        (x, y, z)
+    }
+
+    /// Convert ECEF -> ENU relative to reference point (lat0, lon0, h0) on this ellipsoid.
+    /// lat0, lon0 in degrees; h0 in meters
+    /// Returns (east, north, up) in meters
+    pub fn ecef_to_enu(
+        &self,
+        x: f64,
+        y: f64,
+        z: f64,
+        lat0_deg: f64,
+        lon0_deg: f64,
+        h0: f64,
+    ) -> (f64, f64, f64) {
+        // ECEF of the reference point
+        let (x0, y0, z0) = self.geodetic_to_ecef(lat0_deg, lon0_deg, h0);
+
+        // Deltas
+        let dx = x - x0;
+        let dy = y - y0;
+        let dz = z - z0;
+
+        // Angles of the reference point (radians)
+        let lat0 = deg_to_rad(lat0_deg);
+        let lon0 = deg_to_rad(lon0_deg);
+
+        let sin_lat0 = lat0.sin();
+        let cos_lat0 = lat0.cos();
+        let sin_lon0 = lon0.sin();
+        let cos_lon0 = lon0.cos();
+
+        // Rotation matrix for ENU
+        let east = -sin_lon0 * dx + cos_lon0 * dy;
+        let north = -sin_lat0 * cos_lon0 * dx - sin_lat0 * sin_lon0 * dy + cos_lat0 * dz;
+        let up = cos_lat0 * cos_lon0 * dx + cos_lat0 * sin_lon0 * dy + sin_lat0 * dz;
+
+        (east, north, up)
+    }
+
+    /// Convenience wrapper: (lat, lon, h) -> (e, n, u) relative to reference (lat0, lon0, h0)
+    pub fn llh_to_enu(
+        &self,
+        lat_deg: f64,
+        lon_deg: f64,
+        h: f64,
+        lat0_deg: f64,
+        lon0_deg: f64,
+        h0: f64,
+    ) -> (f64, f64, f64) {
+        let (x, y, z) = self.geodetic_to_ecef(lat_deg, lon_deg, h);
+        self.ecef_to_enu(x, y, z, lat0_deg, lon0_deg, h0)
+    }
+
+    /// Convert ENU -> ECEF relative to reference point (lat0, lon0, h0) on this ellipsoid.
+    /// lat0, lon0 in degrees; h0 in meters; e, n, u in meters
+    pub fn enu_to_ecef(
+        &self,
+        e: f64,
+        n: f64,
+        u: f64,
+        lat0_deg: f64,
+        lon0_deg: f64,
+        h0: f64,
+    ) -> (f64, f64, f64) {
+        // ECEF of the reference point
+        let (x0, y0, z0) = self.geodetic_to_ecef(lat0_deg, lon0_deg, h0);
+
+        let lat0 = deg_to_rad(lat0_deg);
+        let lon0 = deg_to_rad(lon0_deg);
+
+        let sin_lat0 = lat0.sin();
+        let cos_lat0 = lat0.cos();
+        let sin_lon0 = lon0.sin();
+        let cos_lon0 = lon0.cos();
+
+        // Transpose of the ENU rotation matrix
+        let dx = -sin_lon0 * e - sin_lat0 * cos_lon0 * n + cos_lat0 * cos_lon0 * u;
+        let dy = cos_lon0 * e - sin_lat0 * sin_lon0 * n + cos_lat0 * sin_lon0 * u;
+        let dz = cos_lat0 * n + sin_lat0 * u;
+
+        (x0 + dx, y0 + dy, z0 + dz)
+    }
+
+    /// Convert ECEF (X, Y, Z) to geodetic (lat, lon, h) on this ellipsoid, using Bowring's
+    /// iterative method. Returns (lat_deg, lon_deg, h) in degrees and meters.
+    pub fn ecef_to_geodetic(&self, x: f64, y: f64, z: f64) -> (f64, f64, f64) {
+        let lon = y.atan2(x);
+        let p = (x * x + y * y).sqrt();
+
+        // Polar edge case: on the rotation axis, longitude is undefined and there is no
+        // meaningful iteration to run.
+        if p < 1e-12 {
+            let b = self.b();
+            let lat = if z >= 0.0 { 90.0 } else { -90.0 };
+            let h = z.abs() - b;
+            return (lat, lon.to_degrees(), h);
+        }
+
+        let mut lat = z.atan2(p * (1.0 - self.e2));
+        let mut iterations = 0;
+        loop {
+            let sin_lat = lat.sin();
+            let n = self.a / (1.0 - self.e2 * sin_lat * sin_lat).sqrt();
+            let h = p / lat.cos() - n;
+            let lat_new = z.atan2(p * (1.0 - self.e2 * n / (n + h)));
+            let converged = (lat_new - lat).abs() < 1e-12;
+            lat = lat_new;
+
+            iterations += 1;
+            if converged || iterations > 200 {
+                break;
+            }
+        }
+
+        let sin_lat = lat.sin();
+        let n = self.a / (1.0 - self.e2 * sin_lat * sin_lat).sqrt();
+        let h = p / lat.cos() - n;
+
+        (lat.to_degrees(), lon.to_degrees(), h)
+    }
+
+    /// Convenience wrapper: (e, n, u) relative to reference (lat0, lon0, h0) -> (lat, lon, h)
+    pub fn enu_to_llh(
+        &self,
+        e: f64,
+        n: f64,
+        u: f64,
+        lat0_deg: f64,
+        lon0_deg: f64,
+        h0: f64,
+    ) -> (f64, f64, f64) {
+        let (x, y, z) = self.enu_to_ecef(e, n, u, lat0_deg, lon0_deg, h0);
+        self.ecef_to_geodetic(x, y, z)
+    }
+
+    /// Meridian arc length from the equator to geodetic latitude `lat` (radians), on this
+    /// ellipsoid.
+    fn meridian_arc(&self, lat: f64) -> f64 {
+        let e2 = self.e2;
+        let e4 = e2 * e2;
+        let e6 = e4 * e2;
+
+        self.a
+            * ((1.0 - e2 / 4.0 - 3.0 * e4 / 64.0 - 5.0 * e6 / 256.0) * lat
+                - (3.0 * e2 / 8.0 + 3.0 * e4 / 32.0 + 45.0 * e6 / 1024.0) * (2.0 * lat).sin()
+                + (15.0 * e4 / 256.0 + 45.0 * e6 / 1024.0) * (4.0 * lat).sin()
+                - (35.0 * e6 / 3072.0) * (6.0 * lat).sin())
+    }
+
+    /// UTM zone and central meridian (degrees) for a given longitude.
+    fn utm_zone(lon_deg: f64) -> (u8, f64) {
+        // lon_deg == 180.0 falls on the same boundary as -180.0 and belongs to zone 60, not
+        // the nonexistent zone 61.
+        let zone = (((lon_deg + 180.0) / 6.0).floor() as u8 + 1).min(60);
+        let lon0_deg = zone as f64 * 6.0 - 183.0;
+        (zone, lon0_deg)
+    }
+
+    /// Convert geodetic (lat, lon) to UTM (zone, hemisphere, easting, northing) on this
+    /// ellipsoid, using the Redfearn/Karney transverse-Mercator series with scale factor
+    /// `k0 = 0.9996`.
+    pub fn geodetic_to_utm(&self, lat_deg: f64, lon_deg: f64) -> (u8, Hemisphere, f64, f64) {
+        const K0: f64 = 0.9996;
+
+        let (zone, lon0_deg) = Self::utm_zone(lon_deg);
+        let hemisphere = if lat_deg >= 0.0 { Hemisphere::North } else { Hemisphere::South };
+
+        let lat = deg_to_rad(lat_deg);
+        let d_lon = deg_to_rad(lon_deg - lon0_deg);
+
+        let e2 = self.e2;
+        let ep2 = e2 / (1.0 - e2);
 
-    let n = A / (1.0 - E2 * sin_lat * sin_lat).sqrt();
+        let sin_lat = lat.sin();
+        let cos_lat = lat.cos();
+        let tan_lat = lat.tan();
 
-    let x = (n + h) * cos_lat * cos_lon;
-    let y = (n + h) * cos_lat * sin_lon;
-    let z = (n * (1.0 - E2) + h) * sin_lat;
+        let n = self.a / (1.0 - e2 * sin_lat * sin_lat).sqrt();
+        let t = tan_lat * tan_lat;
+        let c = ep2 * cos_lat * cos_lat;
+        let a1 = d_lon * cos_lat;
+        let m = self.meridian_arc(lat);
 
-    (x, y, z)
+        let easting = K0
+            * n
+            * (a1
+                + (1.0 - t + c) * a1.powi(3) / 6.0
+                + (5.0 - 18.0 * t + t * t + 72.0 * c - 58.0 * ep2) * a1.powi(5) / 120.0)
+            + 500_000.0;
+
+        let mut northing = K0
+            * (m
+                + n * tan_lat
+                    * (a1.powi(2) / 2.0
+                        + (5.0 - t + 9.0 * c + 4.0 * c * c) * a1.powi(4) / 24.0
+                        + (61.0 - 58.0 * t + t * t + 600.0 * c - 330.0 * ep2) * a1.powi(6)
+                            / 720.0));
+
+        if hemisphere == Hemisphere::South {
+            northing += 10_000_000.0;
+        }
+
+        (zone, hemisphere, easting, northing)
+    }
+
+    /// Convert UTM (zone, hemisphere, easting, northing) back to geodetic (lat, lon) on this
+    /// ellipsoid, inverting the Redfearn/Karney series.
+    pub fn utm_to_geodetic(
+        &self,
+        zone: u8,
+        hemisphere: Hemisphere,
+        easting: f64,
+        northing: f64,
+    ) -> (f64, f64) {
+        const K0: f64 = 0.9996;
+
+        let lon0_deg = zone as f64 * 6.0 - 183.0;
+
+        let e2 = self.e2;
+        let ep2 = e2 / (1.0 - e2);
+        let e1 = (1.0 - (1.0 - e2).sqrt()) / (1.0 + (1.0 - e2).sqrt());
+
+        let x = easting - 500_000.0;
+        let y = if hemisphere == Hemisphere::South {
+            northing - 10_000_000.0
+        } else {
+            northing
+        };
+
+        let m = y / K0;
+        let mu = m / (self.a * (1.0 - e2 / 4.0 - 3.0 * e2 * e2 / 64.0 - 5.0 * e2.powi(3) / 256.0));
+
+        let lat1 = mu
+            + (3.0 * e1 / 2.0 - 27.0 * e1.powi(3) / 32.0) * (2.0 * mu).sin()
+            + (21.0 * e1 * e1 / 16.0 - 55.0 * e1.powi(4) / 32.0) * (4.0 * mu).sin()
+            + (151.0 * e1.powi(3) / 96.0) * (6.0 * mu).sin()
+            + (1097.0 * e1.powi(4) / 512.0) * (8.0 * mu).sin();
+
+        let sin_lat1 = lat1.sin();
+        let cos_lat1 = lat1.cos();
+        let tan_lat1 = lat1.tan();
+
+        let c1 = ep2 * cos_lat1 * cos_lat1;
+        let t1 = tan_lat1 * tan_lat1;
+        let n1 = self.a / (1.0 - e2 * sin_lat1 * sin_lat1).sqrt();
+        let r1 = self.a * (1.0 - e2) / (1.0 - e2 * sin_lat1 * sin_lat1).powf(1.5);
+        let d = x / (n1 * K0);
+
+        let lat = lat1
+            - (n1 * tan_lat1 / r1)
+                * (d * d / 2.0
+                    - (5.0 + 3.0 * t1 + 10.0 * c1 - 4.0 * c1 * c1 - 9.0 * ep2) * d.powi(4) / 24.0
+                    + (61.0 + 90.0 * t1 + 298.0 * c1 + 45.0 * t1 * t1 - 252.0 * ep2
+                        - 3.0 * c1 * c1)
+                        * d.powi(6)
+                        / 720.0);
+
+        let lon = deg_to_rad(lon0_deg)
+            + (d - (1.0 + 2.0 * t1 + c1) * d.powi(3) / 6.0
+                + (5.0 - 2.0 * c1 + 28.0 * t1 - 3.0 * c1 * c1 + 8.0 * ep2 + 24.0 * t1 * t1)
+                    * d.powi(5)
+                    / 120.0)
+                / cos_lat1;
+
+        (lat.to_degrees(), lon.to_degrees())
+    }
+
+    /// Solve the geodesic inverse problem: distance and forward/back azimuths between two
+    /// points, using Vincenty's iteration on this ellipsoid.
+    /// Returns (distance_m, azimuth1_deg, azimuth2_deg).
+    pub fn inverse(&self, lat1_deg: f64, lon1_deg: f64, lat2_deg: f64, lon2_deg: f64) -> (f64, f64, f64) {
+        let f = self.f();
+        let b = self.b();
+
+        let lat1 = deg_to_rad(lat1_deg);
+        let lat2 = deg_to_rad(lat2_deg);
+        let l = deg_to_rad(lon2_deg - lon1_deg);
+
+        let u1 = ((1.0 - f) * lat1.tan()).atan();
+        let u2 = ((1.0 - f) * lat2.tan()).atan();
+        let (sin_u1, cos_u1) = u1.sin_cos();
+        let (sin_u2, cos_u2) = u2.sin_cos();
+
+        let mut lambda = l;
+        let mut sin_sigma;
+        let mut cos_sigma;
+        let mut sigma;
+        let mut cos_sq_alpha;
+        let mut cos_2sigma_m;
+        let mut sin_alpha;
+
+        let mut iterations = 0;
+        loop {
+            let (sin_lambda, cos_lambda) = lambda.sin_cos();
+            sin_sigma = ((cos_u2 * sin_lambda).powi(2)
+                + (cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda).powi(2))
+            .sqrt();
+            if sin_sigma == 0.0 {
+                // Coincident points.
+                return (0.0, 0.0, 0.0);
+            }
+            cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+            sigma = sin_sigma.atan2(cos_sigma);
+            sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+            cos_sq_alpha = 1.0 - sin_alpha * sin_alpha;
+            cos_2sigma_m = if cos_sq_alpha.abs() < 1e-12 {
+                // Equatorial line.
+                0.0
+            } else {
+                cos_sigma - 2.0 * sin_u1 * sin_u2 / cos_sq_alpha
+            };
+            let c = f / 16.0 * cos_sq_alpha * (4.0 + f * (4.0 - 3.0 * cos_sq_alpha));
+            let lambda_prev = lambda;
+            lambda = l
+                + (1.0 - c)
+                    * f
+                    * sin_alpha
+                    * (sigma
+                        + c * sin_sigma
+                            * (cos_2sigma_m + c * cos_sigma * (-1.0 + 2.0 * cos_2sigma_m * cos_2sigma_m)));
+
+            iterations += 1;
+            if (lambda - lambda_prev).abs() < 1e-12 {
+                break;
+            }
+            if iterations > 200 {
+                // Antipodal or other slow-converging case: use the last iterate.
+                break;
+            }
+        }
+
+        let u_sq = cos_sq_alpha * (self.a * self.a - b * b) / (b * b);
+        let big_a = 1.0 + u_sq / 16384.0 * (4096.0 + u_sq * (-768.0 + u_sq * (320.0 - 175.0 * u_sq)));
+        let big_b = u_sq / 1024.0 * (256.0 + u_sq * (-128.0 + u_sq * (74.0 - 47.0 * u_sq)));
+        let delta_sigma = big_b
+            * sin_sigma
+            * (cos_2sigma_m
+                + big_b / 4.0
+                    * (cos_sigma * (-1.0 + 2.0 * cos_2sigma_m * cos_2sigma_m)
+                        - big_b / 6.0
+                            * cos_2sigma_m
+                            * (-3.0 + 4.0 * sin_sigma * sin_sigma)
+                            * (-3.0 + 4.0 * cos_2sigma_m * cos_2sigma_m)));
+
+        let distance = b * big_a * (sigma - delta_sigma);
+
+        let (sin_lambda, cos_lambda) = lambda.sin_cos();
+        let azimuth1 = (cos_u2 * sin_lambda).atan2(cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda);
+        let azimuth2 = (cos_u1 * sin_lambda).atan2(-sin_u1 * cos_u2 + cos_u1 * sin_u2 * cos_lambda);
+
+        (
+            distance,
+            normalize_deg_360(azimuth1.to_degrees()),
+            normalize_deg_360(azimuth2.to_degrees()),
+        )
+    }
+
+    /// Solve the geodesic direct problem: the point reached by travelling `distance_m` from
+    /// (lat1, lon1) along initial azimuth `azimuth1_deg`, using Vincenty's iteration on this
+    /// ellipsoid. Returns (lat2_deg, lon2_deg, azimuth2_deg).
+    pub fn direct(&self, lat1_deg: f64, lon1_deg: f64, azimuth1_deg: f64, distance_m: f64) -> (f64, f64, f64) {
+        let f = self.f();
+        let b = self.b();
+
+        let lat1 = deg_to_rad(lat1_deg);
+        let alpha1 = deg_to_rad(azimuth1_deg);
+
+        let u1 = ((1.0 - f) * lat1.tan()).atan();
+        let (sin_u1, cos_u1) = u1.sin_cos();
+        let (sin_alpha1, cos_alpha1) = alpha1.sin_cos();
+
+        let sigma1 = sin_u1.atan2(cos_u1 * cos_alpha1);
+        let sin_alpha = cos_u1 * sin_alpha1;
+        let cos_sq_alpha = 1.0 - sin_alpha * sin_alpha;
+        let u_sq = cos_sq_alpha * (self.a * self.a - b * b) / (b * b);
+        let big_a = 1.0 + u_sq / 16384.0 * (4096.0 + u_sq * (-768.0 + u_sq * (320.0 - 175.0 * u_sq)));
+        let big_b = u_sq / 1024.0 * (256.0 + u_sq * (-128.0 + u_sq * (74.0 - 47.0 * u_sq)));
+
+        let mut sigma = distance_m / (b * big_a);
+        let mut cos_2sigma_m;
+        let mut iterations = 0;
+        loop {
+            cos_2sigma_m = (2.0 * sigma1 + sigma).cos();
+            let (sin_sigma, cos_sigma) = sigma.sin_cos();
+            let delta_sigma = big_b
+                * sin_sigma
+                * (cos_2sigma_m
+                    + big_b / 4.0
+                        * (cos_sigma * (-1.0 + 2.0 * cos_2sigma_m * cos_2sigma_m)
+                            - big_b / 6.0
+                                * cos_2sigma_m
+                                * (-3.0 + 4.0 * sin_sigma * sin_sigma)
+                                * (-3.0 + 4.0 * cos_2sigma_m * cos_2sigma_m)));
+            let sigma_prev = sigma;
+            sigma = distance_m / (b * big_a) + delta_sigma;
+
+            iterations += 1;
+            if (sigma - sigma_prev).abs() < 1e-12 || iterations > 200 {
+                break;
+            }
+        }
+
+        let (sin_sigma, cos_sigma) = sigma.sin_cos();
+        let tmp = sin_u1 * sin_sigma - cos_u1 * cos_sigma * cos_alpha1;
+        let lat2 = (sin_u1 * cos_sigma + cos_u1 * sin_sigma * cos_alpha1)
+            .atan2((1.0 - f) * (sin_alpha * sin_alpha + tmp * tmp).sqrt());
+        let lambda = (sin_sigma * sin_alpha1).atan2(cos_u1 * cos_sigma - sin_u1 * sin_sigma * cos_alpha1);
+        let c = f / 16.0 * cos_sq_alpha * (4.0 + f * (4.0 - 3.0 * cos_sq_alpha));
+        let l = lambda
+            - (1.0 - c)
+                * f
+                * sin_alpha
+                * (sigma + c * sin_sigma * (cos_2sigma_m + c * cos_sigma * (-1.0 + 2.0 * cos_2sigma_m * cos_2sigma_m)));
+
+        let lon2 = normalize_lon_deg(lon1_deg + l.to_degrees());
+        let azimuth2 = sin_alpha.atan2(-tmp);
+
+        (lat2.to_degrees(), lon2, normalize_deg_360(azimuth2.to_degrees()))
+    }
+
+    /// Convenience wrapper: (lat, lon, h) relative to reference (lat0, lon0, h0) -> (azimuth_deg,
+    /// elevation_deg, range_m), on this ellipsoid.
+    pub fn llh_to_aer(
+        &self,
+        lat_deg: f64,
+        lon_deg: f64,
+        h: f64,
+        lat0_deg: f64,
+        lon0_deg: f64,
+        h0: f64,
+    ) -> (f64, f64, f64) {
+        let (e, n, u) = self.llh_to_enu(lat_deg, lon_deg, h, lat0_deg, lon0_deg, h0);
+        enu_to_aer(e, n, u)
+    }
+
+    /// Convenience wrapper: (azimuth_deg, elevation_deg, range_m) relative to reference (lat0,
+    /// lon0, h0) -> (lat_deg, lon_deg, h), on this ellipsoid.
+    pub fn aer_to_llh(
+        &self,
+        azimuth_deg: f64,
+        elevation_deg: f64,
+        range: f64,
+        lat0_deg: f64,
+        lon0_deg: f64,
+        h0: f64,
+    ) -> (f64, f64, f64) {
+        let (e, n, u) = aer_to_enu(azimuth_deg, elevation_deg, range);
+        self.enu_to_llh(e, n, u, lat0_deg, lon0_deg, h0)
+    }
+}
+
+/// Normalize an angle in degrees to the range [0, 360).
+fn normalize_deg_360(deg: f64) -> f64 {
+    let wrapped = deg % 360.0;
+    if wrapped < 0.0 {
+        wrapped + 360.0
+    } else {
+        wrapped
+    }
+}
+
+/// Normalize a longitude in degrees to the range (-180, 180], wrapping across the
+/// antimeridian.
+fn normalize_lon_deg(deg: f64) -> f64 {
+    let wrapped = normalize_deg_360(deg + 180.0) - 180.0;
+    if wrapped <= -180.0 {
+        wrapped + 360.0
+    } else {
+        wrapped
+    }
+}
+
+/// Hemisphere of a UTM coordinate, determining the false-northing convention.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hemisphere {
+    North,
+    South,
 }
 
-/// Convert ECEF -> ENU relative to reference point (lat0, lon0, h0)
+/// Convert geodetic (lat, lon, h) to ECEF (X, Y, Z) on WGS-84.
+/// lat, lon in degrees; h in meters
+pub fn geodetic_to_ecef(lat_deg: f64, lon_deg: f64, h: f64) -> (f64, f64, f64) {
+    Ellipsoid::wgs84().geodetic_to_ecef(lat_deg, lon_deg, h)
+}
+
+/// Convert ECEF -> ENU relative to reference point (lat0, lon0, h0) on WGS-84.
 /// lat0, lon0 in degrees; h0 in meters
 /// Returns (east, north, up) in meters
 pub fn ecef_to_enu(x: f64, y: f64, z: f64, lat0_deg: f64, lon0_deg: f64, h0: f64) -> (f64, f64, f64) {
-    // ECEF of the reference point
-    let (x0, y0, z0) = geodetic_to_ecef(lat0_deg, lon0_deg, h0);
+    Ellipsoid::wgs84().ecef_to_enu(x, y, z, lat0_deg, lon0_deg, h0)
+}
 
-    // Deltas
-    let dx = x - x0;
-    let dy = y - y0;
-    let dz = z - z0;
+/// Convenience wrapper: (lat, lon, h) -> (e, n, u) relative to reference (lat0, lon0, h0)
+pub fn llh_to_enu(lat_deg: f64, lon_deg: f64, h: f64, lat0_deg: f64, lon0_deg: f64, h0: f64) -> (f64, f64, f64) {
+    Ellipsoid::wgs84().llh_to_enu(lat_deg, lon_deg, h, lat0_deg, lon0_deg, h0)
+}
 
-    // Angles of the reference point (radians)
-    let lat0 = deg_to_rad(lat0_deg);
-    let lon0 = deg_to_rad(lon0_deg);
+/// Convert ENU -> ECEF relative to reference point (lat0, lon0, h0) on WGS-84.
+/// lat0, lon0 in degrees; h0 in meters; e, n, u in meters
+pub fn enu_to_ecef(e: f64, n: f64, u: f64, lat0_deg: f64, lon0_deg: f64, h0: f64) -> (f64, f64, f64) {
+    Ellipsoid::wgs84().enu_to_ecef(e, n, u, lat0_deg, lon0_deg, h0)
+}
 
-    let sin_lat0 = lat0.sin();
-    let cos_lat0 = lat0.cos();
-    let sin_lon0 = lon0.sin();
-    let cos_lon0 = lon0.cos();
+/// Convert ECEF (X, Y, Z) to geodetic (lat, lon, h) on WGS-84 using Bowring's iterative method.
+/// Returns (lat_deg, lon_deg, h) in degrees and meters.
+pub fn ecef_to_geodetic(x: f64, y: f64, z: f64) -> (f64, f64, f64) {
+    Ellipsoid::wgs84().ecef_to_geodetic(x, y, z)
+}
 
-    // Rotation matrix for ENU
-    let east = -sin_lon0 * dx + cos_lon0 * dy;
-    let north = -sin_lat0 * cos_lon0 * dx - sin_lat0 * sin_lon0 * dy + cos_lat0 * dz;
-    let up = cos_lat0 * cos_lon0 * dx + cos_lat0 * sin_lon0 * dy + sin_lat0 * dz;
+/// Convenience wrapper: (e, n, u) relative to reference (lat0, lon0, h0) -> (lat, lon, h)
+pub fn enu_to_llh(e: f64, n: f64, u: f64, lat0_deg: f64, lon0_deg: f64, h0: f64) -> (f64, f64, f64) {
+    Ellipsoid::wgs84().enu_to_llh(e, n, u, lat0_deg, lon0_deg, h0)
+}
 
-    (east, north, up)
+/// Convert geodetic (lat, lon) to UTM (zone, hemisphere, easting, northing) on WGS-84.
+pub fn geodetic_to_utm(lat_deg: f64, lon_deg: f64) -> (u8, Hemisphere, f64, f64) {
+    Ellipsoid::wgs84().geodetic_to_utm(lat_deg, lon_deg)
 }
 
-/// Convenience wrapper: (lat, lon, h) -> (e, n, u) relative to reference (lat0, lon0, h0)
-pub fn llh_to_enu(lat_deg: f64, lon_deg: f64, h: f64, lat0_deg: f64, lon0_deg: f64, h0: f64) -> (f64, f64, f64) {
-    let (x, y, z) = geodetic_to_ecef(lat_deg, lon_deg, h);
-    ecef_to_enu(x, y, z, lat0_deg, lon0_deg, h0)
+/// Convert UTM (zone, hemisphere, easting, northing) back to geodetic (lat, lon) on WGS-84.
+pub fn utm_to_geodetic(zone: u8, hemisphere: Hemisphere, easting: f64, northing: f64) -> (f64, f64) {
+    Ellipsoid::wgs84().utm_to_geodetic(zone, hemisphere, easting, northing)
+}
+
+/// Solve the geodesic inverse problem on WGS-84: distance and forward/back azimuths between
+/// two points. Returns (distance_m, azimuth1_deg, azimuth2_deg).
+pub fn inverse(lat1_deg: f64, lon1_deg: f64, lat2_deg: f64, lon2_deg: f64) -> (f64, f64, f64) {
+    Ellipsoid::wgs84().inverse(lat1_deg, lon1_deg, lat2_deg, lon2_deg)
+}
+
+/// Solve the geodesic direct problem on WGS-84: the point reached by travelling `distance_m`
+/// from (lat1, lon1) along initial azimuth `azimuth1_deg`. Returns (lat2_deg, lon2_deg,
+/// azimuth2_deg).
+pub fn direct(lat1_deg: f64, lon1_deg: f64, azimuth1_deg: f64, distance_m: f64) -> (f64, f64, f64) {
+    Ellipsoid::wgs84().direct(lat1_deg, lon1_deg, azimuth1_deg, distance_m)
+}
+
+/// Convert local ENU (east, north, up) to azimuth/elevation/range.
+/// Returns (azimuth_deg, elevation_deg, range_m), with azimuth normalized to [0, 360).
+pub fn enu_to_aer(e: f64, n: f64, u: f64) -> (f64, f64, f64) {
+    let range = (e * e + n * n + u * u).sqrt();
+    let azimuth = normalize_deg_360(e.atan2(n).to_degrees());
+    let elevation = u.atan2((e * e + n * n).sqrt()).to_degrees();
+
+    (azimuth, elevation, range)
+}
+
+/// Convert azimuth/elevation/range to local ENU (east, north, up).
+pub fn aer_to_enu(azimuth_deg: f64, elevation_deg: f64, range: f64) -> (f64, f64, f64) {
+    let azimuth = deg_to_rad(azimuth_deg);
+    let elevation = deg_to_rad(elevation_deg);
+
+    let e = range * elevation.cos() * azimuth.sin();
+    let n = range * elevation.cos() * azimuth.cos();
+    let u = range * elevation.sin();
+
+    (e, n, u)
+}
+
+/// Convenience wrapper: (lat, lon, h) relative to reference (lat0, lon0, h0) -> (azimuth_deg,
+/// elevation_deg, range_m), on WGS-84.
+pub fn llh_to_aer(
+    lat_deg: f64,
+    lon_deg: f64,
+    h: f64,
+    lat0_deg: f64,
+    lon0_deg: f64,
+    h0: f64,
+) -> (f64, f64, f64) {
+    Ellipsoid::wgs84().llh_to_aer(lat_deg, lon_deg, h, lat0_deg, lon0_deg, h0)
+}
+
+/// Convenience wrapper: (azimuth_deg, elevation_deg, range_m) relative to reference (lat0,
+/// lon0, h0) -> (lat_deg, lon_deg, h), on WGS-84.
+pub fn aer_to_llh(
+    azimuth_deg: f64,
+    elevation_deg: f64,
+    range: f64,
+    lat0_deg: f64,
+    lon0_deg: f64,
+    h0: f64,
+) -> (f64, f64, f64) {
+    Ellipsoid::wgs84().aer_to_llh(azimuth_deg, elevation_deg, range, lat0_deg, lon0_deg, h0)
+}
+
+/// WGS-84 normal gravity at a given geodetic latitude, via Somigliana's formula.
+/// Returns the gravity magnitude in m/s^2.
+pub fn normal_gravity(lat_deg: f64) -> f64 {
+    const NGE: f64 = 9.780_325_335_9; // equatorial normal gravity, m/s^2
+    const K: f64 = 0.001_931_853; // Somigliana's gravity formula constant
+
+    let sin_lat = deg_to_rad(lat_deg).sin();
+    let sin_sq = sin_lat * sin_lat;
+
+    NGE * (1.0 + K * sin_sq) / (1.0 - E2 * sin_sq).sqrt()
+}
+
+/// Geocentric radius of the WGS-84 ellipsoid at a given geodetic latitude: the distance from
+/// Earth's center to the ellipsoid surface. Returns meters.
+pub fn geocentric_radius(lat_deg: f64) -> f64 {
+    let b = Ellipsoid::wgs84().b();
+    let lat = deg_to_rad(lat_deg);
+    let sin_lat = lat.sin();
+    let cos_lat = lat.cos();
+
+    let num = (A * A * cos_lat).powi(2) + (b * b * sin_lat).powi(2);
+    let den = (A * cos_lat).powi(2) + (b * sin_lat).powi(2);
+
+    (num / den).sqrt()
 }
 
 /// Compute centroid latitude/longitude for a list of points (lat, lon) in degrees.
@@ -108,4 +724,182 @@ mod tests {
         assert!((n - approx_n).abs() < 5.0);
         assert!(e.abs() > 0.0);
     }
+
+    #[test]
+    fn llh_enu_llh_roundtrip() {
+        let lat0 = 42.680067;
+        let lon0 = 3.034061;
+        let h0 = 0.0;
+
+        let lat1 = 42.680499;
+        let lon1 = 3.035775;
+        let h1 = 1.0;
+
+        let (e, n, u) = llh_to_enu(lat1, lon1, h1, lat0, lon0, h0);
+        let (lat2, lon2, h2) = enu_to_llh(e, n, u, lat0, lon0, h0);
+
+        assert!((lat1 - lat2).abs() < 1e-9);
+        assert!((lon1 - lon2).abs() < 1e-9);
+        assert!((h1 - h2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn grs80_close_to_wgs84() {
+        let grs80 = Ellipsoid::grs80();
+        let wgs84 = Ellipsoid::wgs84();
+        let (x1, y1, z1) = wgs84.geodetic_to_ecef(50.0, 10.0, 200.0);
+        let (x2, y2, z2) = grs80.geodetic_to_ecef(50.0, 10.0, 200.0);
+        assert!((x1 - x2).abs() < 1e-3);
+        assert!((y1 - y2).abs() < 1e-3);
+        assert!((z1 - z2).abs() < 1e-3);
+    }
+
+    #[test]
+    fn utm_roundtrip() {
+        let lat = 42.680067;
+        let lon = 3.034061;
+
+        let (zone, hemisphere, easting, northing) = geodetic_to_utm(lat, lon);
+        assert_eq!(zone, 31);
+        assert_eq!(hemisphere, Hemisphere::North);
+
+        let (lat2, lon2) = utm_to_geodetic(zone, hemisphere, easting, northing);
+        assert!((lat - lat2).abs() < 1e-8);
+        assert!((lon - lon2).abs() < 1e-8);
+    }
+
+    #[test]
+    fn utm_southern_hemisphere_false_northing() {
+        let lat = -33.8688;
+        let lon = 151.2093;
+
+        let (zone, hemisphere, easting, northing) = geodetic_to_utm(lat, lon);
+        assert_eq!(zone, 56);
+        assert_eq!(hemisphere, Hemisphere::South);
+        assert!(northing > 5_000_000.0);
+
+        let (lat2, lon2) = utm_to_geodetic(zone, hemisphere, easting, northing);
+        assert!((lat - lat2).abs() < 1e-8);
+        assert!((lon - lon2).abs() < 1e-8);
+    }
+
+    #[test]
+    fn utm_zone_clamped_at_antimeridian() {
+        let (zone_pos, ..) = geodetic_to_utm(10.0, 180.0);
+        let (zone_neg, ..) = geodetic_to_utm(10.0, -180.0);
+        assert_eq!(zone_pos, 60);
+        assert_eq!(zone_neg, 1);
+    }
+
+    #[test]
+    fn geodesic_inverse_is_symmetric() {
+        let (lat1, lon1) = (-37.951033, 144.424868);
+        let (lat2, lon2) = (-37.652818, 143.926495);
+
+        let (distance_fwd, azimuth1_fwd, azimuth2_fwd) = inverse(lat1, lon1, lat2, lon2);
+        let (distance_rev, azimuth1_rev, azimuth2_rev) = inverse(lat2, lon2, lat1, lon1);
+
+        // azimuth2 is the forward azimuth continuing past the destination, so the reversed
+        // inverse gives azimuths rotated 180 degrees from the forward ones.
+        assert!((distance_fwd - distance_rev).abs() < 1e-6);
+        let diff1 = normalize_deg_360(azimuth1_fwd - azimuth2_rev - 180.0);
+        let diff2 = normalize_deg_360(azimuth2_fwd - azimuth1_rev - 180.0);
+        assert!(diff1.min(360.0 - diff1) < 1e-8);
+        assert!(diff2.min(360.0 - diff2) < 1e-8);
+    }
+
+    #[test]
+    fn geodesic_direct_inverse_roundtrip() {
+        let lat1 = 48.8566;
+        let lon1 = 2.3522;
+        let azimuth1 = 45.0;
+        let distance = 10_000.0;
+
+        let (lat2, lon2, _azimuth2) = direct(lat1, lon1, azimuth1, distance);
+        let (distance2, azimuth1b, _azimuth2b) = inverse(lat1, lon1, lat2, lon2);
+
+        assert!((distance - distance2).abs() < 1e-6);
+        assert!((azimuth1 - azimuth1b).abs() < 1e-8);
+    }
+
+    #[test]
+    fn geodesic_direct_wraps_across_antimeridian() {
+        let (_lat2, lon2, _azimuth2) = direct(10.0, 170.0, 90.0, 2_000_000.0);
+        assert!((-180.0..=180.0).contains(&lon2));
+        assert!(lon2 < 0.0);
+    }
+
+    #[test]
+    fn enu_aer_roundtrip() {
+        let (e, n, u) = (100.0, 200.0, 50.0);
+        let (azimuth, elevation, range) = enu_to_aer(e, n, u);
+        let (e2, n2, u2) = aer_to_enu(azimuth, elevation, range);
+
+        assert!((e - e2).abs() < 1e-9);
+        assert!((n - n2).abs() < 1e-9);
+        assert!((u - u2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn enu_to_aer_due_east() {
+        let (azimuth, elevation, range) = enu_to_aer(100.0, 0.0, 0.0);
+        assert!((azimuth - 90.0).abs() < 1e-9);
+        assert!(elevation.abs() < 1e-9);
+        assert!((range - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn llh_aer_llh_roundtrip() {
+        let lat0 = 42.680067;
+        let lon0 = 3.034061;
+        let h0 = 0.0;
+
+        let lat1 = 42.680499;
+        let lon1 = 3.035775;
+        let h1 = 1.0;
+
+        let (azimuth, elevation, range) = llh_to_aer(lat1, lon1, h1, lat0, lon0, h0);
+        let (lat2, lon2, h2) = aer_to_llh(azimuth, elevation, range, lat0, lon0, h0);
+
+        assert!((lat1 - lat2).abs() < 1e-9);
+        assert!((lon1 - lon2).abs() < 1e-9);
+        assert!((h1 - h2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn llh_aer_llh_roundtrip_on_grs80() {
+        let grs80 = Ellipsoid::grs80();
+
+        let lat0 = 42.680067;
+        let lon0 = 3.034061;
+        let h0 = 0.0;
+
+        let lat1 = 42.680499;
+        let lon1 = 3.035775;
+        let h1 = 1.0;
+
+        let (azimuth, elevation, range) = grs80.llh_to_aer(lat1, lon1, h1, lat0, lon0, h0);
+        let (lat2, lon2, h2) = grs80.aer_to_llh(azimuth, elevation, range, lat0, lon0, h0);
+
+        assert!((lat1 - lat2).abs() < 1e-9);
+        assert!((lon1 - lon2).abs() < 1e-9);
+        assert!((h1 - h2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn normal_gravity_equator_and_pole() {
+        // At the equator, Somigliana's formula reduces to the equatorial constant; at the
+        // pole it reduces to nge * (1 + k) / sqrt(1 - e2).
+        assert!((normal_gravity(0.0) - 9.780_325_335_9).abs() < 1e-9);
+        let expected_pole = 9.780_325_335_9 * 1.001_931_853 / (1.0 - E2).sqrt();
+        assert!((normal_gravity(90.0) - expected_pole).abs() < 1e-9);
+        assert!(normal_gravity(90.0) > normal_gravity(0.0));
+    }
+
+    #[test]
+    fn geocentric_radius_equator_and_pole() {
+        let b = Ellipsoid::wgs84().b();
+        assert!((geocentric_radius(0.0) - A).abs() < 1e-6);
+        assert!((geocentric_radius(90.0) - b).abs() < 1e-6);
+    }
 }